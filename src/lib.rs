@@ -40,7 +40,7 @@ use core::{
 extern crate alloc;
 
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
 use futures_core::Stream;
 use pin_project_lite::pin_project;
 
@@ -70,6 +70,21 @@ pub trait StreamExt: Stream + Sized {
         DedupByKey::new(self, key_fn)
     }
 
+    /// Deduplicate consecutive items using the given equality function.
+    ///
+    /// Like [`dedup`][Self::dedup], `DedupBy` keeps a clone of the value that
+    /// was produced last so items can be yielded immediately. Use this
+    /// instead of `dedup` when `Self::Item` doesn't implement `PartialEq`, or
+    /// instead of `dedup_by_key` when there is no cheap key to extract, e.g.
+    /// when comparing only selected fields or with some tolerance.
+    fn dedup_by<F>(self, eq: F) -> DedupBy<Self, F>
+    where
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        DedupBy::new(self, eq)
+    }
+
     /// Buffer the items from `self` until `batch_done_stream` produces a value,
     /// and return all buffered values in one batch.
     ///
@@ -89,6 +104,82 @@ pub trait StreamExt: Stream + Sized {
         BatchWith::new(self, batch_done_stream)
     }
 
+    /// Like [`batch_with`][Self::batch_with], but additionally flushes the
+    /// current batch as soon as it reaches `max_len` items, without waiting
+    /// for `batch_done_stream` to produce a value.
+    ///
+    /// This bounds the memory used for buffering, at the cost of possibly
+    /// producing undersized batches compared to `batch_with` alone.
+    #[cfg(feature = "alloc")]
+    fn batch_with_max<S>(self, batch_done_stream: S, max_len: usize) -> BatchWith<Self, S>
+    where
+        S: Stream<Item = ()>,
+    {
+        BatchWith::new_with_max(self, batch_done_stream, max_len)
+    }
+
+    /// Sample the latest item from `self` each time `trigger_stream` produces
+    /// a value.
+    ///
+    /// Unlike [`batch_with`][Self::batch_with], only the single newest item
+    /// from `self` is kept around rather than all items produced since the
+    /// last trigger, so no item is emitted if `trigger_stream` fires before
+    /// `self` has produced anything since the last sample.
+    ///
+    /// Examples for possible `trigger_stream`s:
+    ///
+    /// - `futures_channel::mpsc::Receiver<()>`
+    /// - `tokio_stream::wrappers::IntervalStream` with its item type mapped to
+    ///   `()` using `.map(|_| ())` (`use tokio_stream::StreamExt` for `map`)
+    ///
+    /// Equivalent to RxJS'es
+    /// [`sample`](https://rxjs.dev/api/index/function/sample).
+    fn sample<T>(self, trigger_stream: T) -> Sample<Self, T>
+    where
+        Self::Item: Clone,
+        T: Stream<Item = ()>,
+    {
+        Sample::new(self, trigger_stream)
+    }
+
+    /// Combine this stream with another one, yielding a tuple of the latest
+    /// item from each whenever either stream produces a new item.
+    ///
+    /// The combined stream does not produce anything until both streams have
+    /// produced at least one item. Once that is the case, it yields a new
+    /// pair whenever either of the two streams has produced new items, using
+    /// the other stream's last known value. Items that are ready at the same
+    /// time are not paired individually: only one pair, holding the newest
+    /// item of each stream, is yielded for them. The combined stream stays open
+    /// as long as at least one of the two streams is still open, using the
+    /// closed stream's last known value for the remainder of its lifetime.
+    ///
+    /// Equivalent to RxJS'es
+    /// [`combineLatest`](https://rxjs.dev/api/index/function/combineLatest).
+    fn combine_latest<S2>(self, other: S2) -> CombineLatest<Self, S2>
+    where
+        Self::Item: Clone,
+        S2: Stream,
+        S2::Item: Clone,
+    {
+        CombineLatest::new(self, other)
+    }
+
+    /// Merge this stream with another one of the same item type, yielding
+    /// items from whichever one produces a value first.
+    ///
+    /// Unlike [`futures_util::stream::select`], this guarantees fairness: the
+    /// two streams are polled in alternating order, so a continuously-ready
+    /// stream can't starve the other one out.
+    ///
+    /// The combined stream closes once both streams are closed.
+    fn merge<S2>(self, other: S2) -> Merge<Self, S2>
+    where
+        S2: Stream<Item = Self::Item>,
+    {
+        Merge::new(self, other)
+    }
+
     /// Flattens a stream of streams by always keeping one inner stream and
     /// yielding its items until the outer stream produces a new inner stream,
     /// at which point the inner stream to yield items from is switched to the
@@ -102,6 +193,41 @@ pub trait StreamExt: Stream + Sized {
     {
         Switch::new(self)
     }
+
+    /// Flattens a stream of streams by polling all inner streams
+    /// concurrently, yielding their items interleaved in the order they
+    /// arrive.
+    ///
+    /// The combined stream is only closed once the outer stream and every
+    /// inner stream it has produced are exhausted.
+    ///
+    /// Equivalent to RxJS'es
+    /// [`mergeAll`](https://rxjs.dev/api/index/function/mergeAll).
+    #[cfg(feature = "alloc")]
+    fn merge_all(self) -> MergeAll<Self>
+    where
+        Self::Item: Stream,
+    {
+        MergeAll::new(self)
+    }
+
+    /// Flattens a stream of streams by fully draining one inner stream before
+    /// moving on to the next, in the order the outer stream produced them.
+    ///
+    /// Unlike [`switch`][Self::switch], no items are ever dropped; unlike
+    /// [`merge_all`][Self::merge_all], inner streams are not polled
+    /// concurrently, so ordering within and across inner streams is
+    /// preserved.
+    ///
+    /// Equivalent to RxJS'es
+    /// [`concatAll`](https://rxjs.dev/api/index/function/concatAll).
+    #[cfg(feature = "alloc")]
+    fn concat_all(self) -> ConcatAll<Self>
+    where
+        Self::Item: Stream,
+    {
+        ConcatAll::new(self)
+    }
 }
 
 impl<S: Stream> StreamExt for S {}
@@ -188,6 +314,50 @@ where
     }
 }
 
+pin_project! {
+    /// Stream adapter produced by [`StreamExt::dedup_by`].
+    pub struct DedupBy<S: Stream, F> {
+        #[pin]
+        inner: S,
+        eq: F,
+        prev_item: Option<S::Item>,
+    }
+}
+
+impl<S: Stream, F> DedupBy<S, F> {
+    fn new(inner: S, eq: F) -> Self {
+        Self { inner, eq, prev_item: None }
+    }
+}
+
+impl<S, F> Stream for DedupBy<S, F>
+where
+    S: Stream,
+    S::Item: Clone,
+    F: FnMut(&S::Item, &S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let mut this = self.project();
+        let next = loop {
+            let opt = ready!(this.inner.as_mut().poll_next(cx));
+            match opt {
+                Some(item) => {
+                    let is_dup =
+                        this.prev_item.as_ref().is_some_and(|prev| (this.eq)(prev, &item));
+                    if !is_dup {
+                        *this.prev_item = Some(item.clone());
+                        break Some(item);
+                    }
+                }
+                None => break None,
+            }
+        };
+        Poll::Ready(next)
+    }
+}
+
 #[cfg(feature = "alloc")]
 pin_project! {
     /// Stream adapter produced by [`StreamExt::batch_with`].
@@ -197,13 +367,18 @@ pin_project! {
         #[pin]
         batch_done_stream: S2,
         batch: Vec<S1::Item>,
+        max_len: Option<usize>,
     }
 }
 
 #[cfg(feature = "alloc")]
 impl<S1: Stream, S2> BatchWith<S1, S2> {
     fn new(primary_stream: S1, batch_done_stream: S2) -> Self {
-        Self { primary_stream, batch_done_stream, batch: Vec::new() }
+        Self { primary_stream, batch_done_stream, batch: Vec::new(), max_len: None }
+    }
+
+    fn new_with_max(primary_stream: S1, batch_done_stream: S2, max_len: usize) -> Self {
+        Self { primary_stream, batch_done_stream, batch: Vec::new(), max_len: Some(max_len) }
     }
 }
 
@@ -220,7 +395,15 @@ where
         loop {
             match this.primary_stream.as_mut().poll_next(cx) {
                 // Primary stream produced a new item
-                Poll::Ready(Some(item)) => this.batch.push(item),
+                Poll::Ready(Some(item)) => {
+                    this.batch.push(item);
+
+                    // The batch reached its size limit, flush it right away
+                    // without waiting for batch_done_stream.
+                    if matches!(*this.max_len, Some(max_len) if this.batch.len() >= max_len) {
+                        return Poll::Ready(Some(mem::take(this.batch)));
+                    }
+                }
                 // Primary stream is closed, don't wait for batch_done_stream
                 Poll::Ready(None) => {
                     let has_pending_items = !this.batch.is_empty();
@@ -245,6 +428,207 @@ where
     }
 }
 
+pin_project! {
+    /// Stream adapter produced by [`StreamExt::sample`].
+    pub struct Sample<S1: Stream, S2> {
+        #[pin]
+        primary_stream: S1,
+        #[pin]
+        trigger_stream: S2,
+        latest: Option<S1::Item>,
+    }
+}
+
+impl<S1: Stream, S2> Sample<S1, S2> {
+    fn new(primary_stream: S1, trigger_stream: S2) -> Self {
+        Self { primary_stream, trigger_stream, latest: None }
+    }
+}
+
+impl<S1, S2> Stream for Sample<S1, S2>
+where
+    S1: Stream,
+    S1::Item: Clone,
+    S2: Stream<Item = ()>,
+{
+    type Item = S1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match this.primary_stream.as_mut().poll_next(cx) {
+                // Primary stream produced a new item, overwriting any previously
+                // stored one.
+                Poll::Ready(Some(item)) => *this.latest = Some(item),
+                // Primary stream is closed, don't wait for trigger_stream.
+                Poll::Ready(None) => return Poll::Ready(this.latest.take()),
+                // Primary stream is pending (and this task is scheduled for wakeup on new items)
+                Poll::Pending => break,
+            }
+        }
+
+        // Primary stream is pending, check the trigger_stream.
+        ready!(this.trigger_stream.poll_next(cx));
+
+        // trigger_stream produced an item …
+        match this.latest.take() {
+            // … but we don't have a value to emit.
+            None => Poll::Pending,
+            // … and we have a value to emit.
+            some => Poll::Ready(some),
+        }
+    }
+}
+
+pin_project! {
+    /// Stream adapter produced by [`StreamExt::combine_latest`].
+    pub struct CombineLatest<S1: Stream, S2: Stream> {
+        #[pin]
+        stream_a: S1,
+        #[pin]
+        stream_b: S2,
+        latest_a: Option<S1::Item>,
+        latest_b: Option<S2::Item>,
+        a_done: bool,
+        b_done: bool,
+    }
+}
+
+impl<S1: Stream, S2: Stream> CombineLatest<S1, S2> {
+    fn new(stream_a: S1, stream_b: S2) -> Self {
+        Self { stream_a, stream_b, latest_a: None, latest_b: None, a_done: false, b_done: false }
+    }
+}
+
+impl<S1, S2> Stream for CombineLatest<S1, S2>
+where
+    S1: Stream,
+    S1::Item: Clone,
+    S2: Stream,
+    S2::Item: Clone,
+{
+    type Item = (S1::Item, S2::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let mut updated = false;
+
+        if !*this.a_done {
+            loop {
+                match this.stream_a.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        *this.latest_a = Some(item);
+                        updated = true;
+                    }
+                    Poll::Ready(None) => {
+                        *this.a_done = true;
+                        break;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+        }
+
+        if !*this.b_done {
+            loop {
+                match this.stream_b.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        *this.latest_b = Some(item);
+                        updated = true;
+                    }
+                    Poll::Ready(None) => {
+                        *this.b_done = true;
+                        break;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+        }
+
+        match (&this.latest_a, &this.latest_b) {
+            (Some(_), Some(_)) if updated => Poll::Ready(Some((
+                this.latest_a.clone().unwrap(),
+                this.latest_b.clone().unwrap(),
+            ))),
+            _ if *this.a_done && *this.b_done => Poll::Ready(None),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+pin_project! {
+    /// Stream adapter produced by [`StreamExt::merge`].
+    pub struct Merge<S1, S2> {
+        #[pin]
+        stream_a: S1,
+        #[pin]
+        stream_b: S2,
+        poll_first: bool,
+        a_done: bool,
+        b_done: bool,
+    }
+}
+
+impl<S1, S2> Merge<S1, S2> {
+    fn new(stream_a: S1, stream_b: S2) -> Self {
+        Self { stream_a, stream_b, poll_first: true, a_done: false, b_done: false }
+    }
+}
+
+impl<S1, S2> Stream for Merge<S1, S2>
+where
+    S1: Stream,
+    S2: Stream<Item = S1::Item>,
+{
+    type Item = S1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Alternate which stream is polled first so that a continuously-ready
+        // stream can't starve the other one out.
+        let poll_a_first = *this.poll_first;
+        *this.poll_first = !poll_a_first;
+
+        macro_rules! poll_a {
+            () => {
+                if !*this.a_done {
+                    match this.stream_a.as_mut().poll_next(cx) {
+                        Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                        Poll::Ready(None) => *this.a_done = true,
+                        Poll::Pending => {}
+                    }
+                }
+            };
+        }
+        macro_rules! poll_b {
+            () => {
+                if !*this.b_done {
+                    match this.stream_b.as_mut().poll_next(cx) {
+                        Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                        Poll::Ready(None) => *this.b_done = true,
+                        Poll::Pending => {}
+                    }
+                }
+            };
+        }
+
+        if poll_a_first {
+            poll_a!();
+            poll_b!();
+        } else {
+            poll_b!();
+            poll_a!();
+        }
+
+        if *this.a_done && *this.b_done {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
 pin_project! {
     /// Stream adapter produced by [`StreamExt::switch`].
     pub struct Switch<S: Stream> {
@@ -316,3 +700,150 @@ where
         }
     }
 }
+
+#[cfg(feature = "alloc")]
+pin_project! {
+    /// Stream adapter produced by [`StreamExt::merge_all`].
+    pub struct MergeAll<S: Stream> {
+        #[pin]
+        outer_stream: S,
+        outer_done: bool,
+        inner_streams: Vec<Pin<Box<S::Item>>>,
+        next_idx: usize,
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: Stream> MergeAll<S> {
+    fn new(outer_stream: S) -> Self {
+        Self { outer_stream, outer_done: false, inner_streams: Vec::new(), next_idx: 0 }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> Stream for MergeAll<S>
+where
+    S: Stream,
+    S::Item: Stream,
+{
+    type Item = <S::Item as Stream>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if !*this.outer_done {
+            loop {
+                match this.outer_stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(inner_stream)) => {
+                        this.inner_streams.push(Box::pin(inner_stream));
+                    }
+                    Poll::Ready(None) => {
+                        *this.outer_done = true;
+                        break;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+        }
+
+        // Poll all currently-live inner streams round-robin, starting from
+        // where the last call left off so a continuously-ready stream can't
+        // starve the others. Closed streams are removed after the scan.
+        let len = this.inner_streams.len();
+        let mut closed = Vec::new();
+        let mut ready_item = None;
+        for offset in 0..len {
+            let idx = (*this.next_idx + offset) % len;
+            match this.inner_streams[idx].as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    *this.next_idx = idx + 1;
+                    ready_item = Some(item);
+                    break;
+                }
+                Poll::Ready(None) => closed.push(idx),
+                Poll::Pending => {}
+            }
+        }
+
+        // Remove closed streams highest-index-first so earlier indices stay
+        // valid as we go.
+        closed.sort_unstable_by(|a, b| b.cmp(a));
+        for idx in closed {
+            this.inner_streams.remove(idx);
+        }
+
+        if let Some(item) = ready_item {
+            return Poll::Ready(Some(item));
+        }
+
+        if *this.outer_done && this.inner_streams.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pin_project! {
+    /// Stream adapter produced by [`StreamExt::concat_all`].
+    pub struct ConcatAll<S: Stream> {
+        #[pin]
+        outer_stream: S,
+        outer_done: bool,
+        inner_streams: VecDeque<Pin<Box<S::Item>>>,
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: Stream> ConcatAll<S> {
+    fn new(outer_stream: S) -> Self {
+        Self { outer_stream, outer_done: false, inner_streams: VecDeque::new() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> Stream for ConcatAll<S>
+where
+    S: Stream,
+    S::Item: Stream,
+{
+    type Item = <S::Item as Stream>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Eagerly pull all ready inner streams from the outer stream into the
+        // queue. Only the front one is polled; the others are not polled
+        // until they reach the front.
+        if !*this.outer_done {
+            loop {
+                match this.outer_stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(inner_stream)) => {
+                        this.inner_streams.push_back(Box::pin(inner_stream));
+                    }
+                    Poll::Ready(None) => {
+                        *this.outer_done = true;
+                        break;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+        }
+
+        loop {
+            match this.inner_streams.front_mut() {
+                Some(inner_stream) => match inner_stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                    Poll::Ready(None) => {
+                        this.inner_streams.pop_front();
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                None => {
+                    return if *this.outer_done { Poll::Ready(None) } else { Poll::Pending };
+                }
+            }
+        }
+    }
+}
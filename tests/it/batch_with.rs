@@ -81,6 +81,38 @@ async fn empty_primary_stream() {
     assert_closed!(batch_stream);
 }
 
+#[tokio::test]
+async fn batch_with_max() -> Result<(), Box<dyn Error>> {
+    // The material we need to drain the batch stream.
+    let (drainer, drainer_receiver) = channel::<()>(1);
+
+    // The material for the batch stream.
+    let (stream_sender, stream_receiver) = unbounded_channel();
+    let mut batch_stream = UnboundedReceiverStream::new(stream_receiver)
+        .batch_with_max(ReceiverStream::new(drainer_receiver), 3);
+
+    // The batch stream is empty, and is pending.
+    assert_pending!(batch_stream);
+
+    // Send fewer items than the max length.
+    stream_sender.send(1)?;
+    stream_sender.send(2)?;
+    assert_pending!(batch_stream);
+
+    // Reaching the max length flushes the batch immediately, without
+    // requiring the drainer to fire.
+    stream_sender.send(3)?;
+    assert_next_eq!(batch_stream, vec![1, 2, 3]);
+    assert_pending!(batch_stream);
+
+    // Smaller batches are still flushed normally by the drainer.
+    stream_sender.send(4)?;
+    drainer.send(()).await?;
+    assert_next_eq!(batch_stream, vec![4]);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn trigger_happy_batch_stream() -> Result<(), Box<dyn Error>> {
     let (drainer, drainer_receiver) = unbounded_channel::<()>();
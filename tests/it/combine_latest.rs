@@ -0,0 +1,47 @@
+use async_rx::StreamExt as _;
+use stream_assert::{assert_closed, assert_next_eq, assert_pending};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+#[test]
+fn combine_latest() {
+    let (tx_a, rx_a) = mpsc::unbounded_channel();
+    let (tx_b, rx_b) = mpsc::unbounded_channel();
+
+    let mut stream =
+        UnboundedReceiverStream::new(rx_a).combine_latest(UnboundedReceiverStream::new(rx_b));
+
+    // Neither stream has produced anything yet.
+    assert_pending!(stream);
+
+    // Only stream_a has produced something so far.
+    tx_a.send(1).unwrap();
+    assert_pending!(stream);
+
+    // Now that stream_b has produced something too, we get a pair.
+    tx_b.send("a").unwrap();
+    assert_next_eq!(stream, (1, "a"));
+    assert_pending!(stream);
+
+    // A new item from either stream produces a new pair, reusing the other's
+    // last known value.
+    tx_a.send(2).unwrap();
+    assert_next_eq!(stream, (2, "a"));
+    assert_pending!(stream);
+
+    tx_b.send("b").unwrap();
+    assert_next_eq!(stream, (2, "b"));
+    assert_pending!(stream);
+
+    // Closing one of the two streams keeps the combined stream alive, reusing
+    // the last known value of the closed stream.
+    drop(tx_a);
+    assert_pending!(stream);
+
+    tx_b.send("c").unwrap();
+    assert_next_eq!(stream, (2, "c"));
+
+    // Only once both streams are closed does the combined stream close.
+    drop(tx_b);
+    assert_closed!(stream);
+}
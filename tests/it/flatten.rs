@@ -0,0 +1,115 @@
+use std::pin::pin;
+
+use async_rx::StreamExt as _;
+use futures_util::{stream, FutureExt, StreamExt};
+use stream_assert::{assert_closed, assert_next_eq, assert_pending};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+#[test]
+fn concat_all_preserves_order() {
+    let stream = stream::iter([
+        stream::iter([1, 2, 3]),
+        stream::iter([4, 5, 6]),
+        stream::iter([7, 8, 9]),
+    ])
+    .concat_all();
+
+    assert_eq!(
+        stream.collect::<Vec<_>>().now_or_never().unwrap(),
+        vec![1, 2, 3, 4, 5, 6, 7, 8, 9]
+    );
+}
+
+#[test]
+fn concat_all_waits_for_inner_stream() {
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    let mut stream = pin!(UnboundedReceiverStream::new(rx).concat_all());
+    assert_pending!(stream);
+
+    let (inner_tx, inner_rx) = mpsc::unbounded_channel();
+    tx.send(UnboundedReceiverStream::new(inner_rx)).unwrap();
+    assert_pending!(stream);
+
+    inner_tx.send(1).unwrap();
+    assert_next_eq!(stream, 1);
+    assert_pending!(stream);
+
+    drop(inner_tx);
+    drop(tx);
+    assert_closed!(stream);
+}
+
+#[test]
+fn merge_all_interleaves() {
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    let mut stream = pin!(UnboundedReceiverStream::new(rx).merge_all());
+    assert_pending!(stream);
+
+    let (tx_a, rx_a) = mpsc::unbounded_channel();
+    let (tx_b, rx_b) = mpsc::unbounded_channel();
+    tx.send(UnboundedReceiverStream::new(rx_a)).unwrap();
+    tx.send(UnboundedReceiverStream::new(rx_b)).unwrap();
+    assert_pending!(stream);
+
+    // Both inner streams are polled, regardless of which one is sent to first.
+    tx_b.send("b").unwrap();
+    assert_next_eq!(stream, "b");
+    assert_pending!(stream);
+
+    tx_a.send("a").unwrap();
+    assert_next_eq!(stream, "a");
+    assert_pending!(stream);
+
+    // Closing one inner stream doesn't affect the other.
+    drop(tx_a);
+    assert_pending!(stream);
+
+    tx_b.send("b2").unwrap();
+    assert_next_eq!(stream, "b2");
+
+    // The combined stream only closes once the outer stream and every inner
+    // stream are exhausted.
+    drop(tx_b);
+    assert_pending!(stream);
+
+    drop(tx);
+    assert_closed!(stream);
+}
+
+#[test]
+fn merge_all_round_robin_no_starvation() {
+    // Regression test: merge_all used to always re-scan its inner streams
+    // starting at index 0 on every poll, so a continuously-ready stream at
+    // index 0 would be returned from on every single call and the others
+    // would never even get polled. The round-robin cursor must instead
+    // persist across calls.
+    let (tx, rx) = mpsc::unbounded_channel();
+    let mut stream = pin!(UnboundedReceiverStream::new(rx).merge_all());
+
+    let (tx0, rx0) = mpsc::unbounded_channel();
+    let (tx1, rx1) = mpsc::unbounded_channel();
+    let (tx2, rx2) = mpsc::unbounded_channel();
+    tx.send(UnboundedReceiverStream::new(rx0)).unwrap();
+    tx.send(UnboundedReceiverStream::new(rx1)).unwrap();
+    tx.send(UnboundedReceiverStream::new(rx2)).unwrap();
+    drop(tx);
+
+    // Stream 1 and 2 each have a single item waiting.
+    tx1.send(1).unwrap();
+    tx2.send(2).unwrap();
+
+    let mut seen = Vec::new();
+    for _ in 0..6 {
+        // Keep stream 0 topped up so it's ready on every single poll.
+        tx0.send(0).unwrap();
+        if let Some(item) = stream.as_mut().next().now_or_never().flatten() {
+            seen.push(item);
+        }
+    }
+
+    assert!(seen.contains(&1), "stream at index 1 was starved: {seen:?}");
+    assert!(seen.contains(&2), "stream at index 2 was starved: {seen:?}");
+}
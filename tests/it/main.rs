@@ -3,6 +3,10 @@ use futures_util::{stream, FutureExt, StreamExt};
 use stream_assert::{assert_closed, assert_next_eq, assert_pending};
 
 mod batch_with;
+mod combine_latest;
+mod flatten;
+mod merge;
+mod sample;
 mod switch;
 
 #[test]
@@ -37,3 +41,13 @@ fn dedup_by_key() {
     let stream = stream::iter([1, 2, 3, 1, 2, 4, 8]).dedup_by_key(|num| num % 2);
     assert_eq!(stream.collect::<Vec<_>>().now_or_never().unwrap(), vec![1, 2, 3, 2]);
 }
+
+#[test]
+fn dedup_by() {
+    let stream = stream::iter([1.0f64, 1.05, 1.2, 2.0, 2.04, 3.0])
+        .dedup_by(|a, b| (a - b).abs() < 0.1);
+    assert_eq!(
+        stream.collect::<Vec<_>>().now_or_never().unwrap(),
+        vec![1.0, 1.2, 2.0, 3.0]
+    );
+}
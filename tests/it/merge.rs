@@ -0,0 +1,57 @@
+use async_rx::StreamExt as _;
+use futures_util::stream;
+use stream_assert::{assert_closed, assert_next_eq, assert_pending};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+#[test]
+fn merge_fairness() {
+    // stream_a is always ready, stream_b never is. Even so, merge must not
+    // starve stream_b: each poll only yields (at most) one item per stream
+    // before the other side is polled.
+    let mut stream = stream::repeat(1).merge(stream::pending::<i32>());
+    assert_next_eq!(stream, 1);
+    assert_next_eq!(stream, 1);
+}
+
+#[test]
+fn merge_alternates_when_both_are_ready() {
+    // Both streams are always ready, so without alternation a naive
+    // implementation that always polls the same stream first would starve
+    // the other one completely. Instead, merge must alternate which stream
+    // is polled first on each call, yielding items from both in lockstep.
+    let mut stream = stream::repeat(1).merge(stream::repeat(2));
+    for _ in 0..3 {
+        assert_next_eq!(stream, 1);
+        assert_next_eq!(stream, 2);
+    }
+}
+
+#[test]
+fn merge_on_channels() {
+    let (tx_a, rx_a) = mpsc::unbounded_channel();
+    let (tx_b, rx_b) = mpsc::unbounded_channel();
+
+    let mut stream =
+        UnboundedReceiverStream::new(rx_a).merge(UnboundedReceiverStream::new(rx_b));
+    assert_pending!(stream);
+
+    tx_a.send(1).unwrap();
+    assert_next_eq!(stream, 1);
+    assert_pending!(stream);
+
+    tx_b.send(2).unwrap();
+    assert_next_eq!(stream, 2);
+    assert_pending!(stream);
+
+    // Closing one stream doesn't close the merged stream.
+    drop(tx_a);
+    assert_pending!(stream);
+
+    tx_b.send(3).unwrap();
+    assert_next_eq!(stream, 3);
+
+    // Only closing both streams closes the merged stream.
+    drop(tx_b);
+    assert_closed!(stream);
+}
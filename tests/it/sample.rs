@@ -0,0 +1,53 @@
+use std::error::Error;
+
+use async_rx::StreamExt as _;
+use stream_assert::{assert_closed, assert_next_eq, assert_pending};
+use tokio::sync::mpsc::{channel, unbounded_channel};
+use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
+
+#[tokio::test]
+async fn sample() -> Result<(), Box<dyn Error>> {
+    // The material we need to trigger a sample.
+    let (trigger, trigger_receiver) = channel::<()>(1);
+
+    // The material for the sampled stream.
+    let (stream_sender, stream_receiver) = unbounded_channel();
+    let mut sample_stream =
+        UnboundedReceiverStream::new(stream_receiver).sample(ReceiverStream::new(trigger_receiver));
+
+    // The sample stream is empty, and is pending.
+    assert_pending!(sample_stream);
+
+    // Triggering without any new items shouldn't emit anything.
+    trigger.send(()).await?;
+    assert_pending!(sample_stream);
+
+    // Send new data onto the sampled stream.
+    stream_sender.send(1)?;
+    stream_sender.send(2)?;
+    stream_sender.send(3)?;
+
+    // The sample stream is still pending until the trigger fires.
+    assert_pending!(sample_stream);
+
+    // Let's trigger a sample.
+    trigger.send(()).await?;
+
+    // Only the newest value is emitted.
+    assert_next_eq!(sample_stream, 3);
+    assert_pending!(sample_stream);
+
+    // Triggering again without new items doesn't re-emit the old value.
+    trigger.send(()).await?;
+    assert_pending!(sample_stream);
+
+    // Send one more item, then close the sampled stream.
+    stream_sender.send(4)?;
+    drop(stream_sender);
+
+    // Closing the primary stream forces it to drain the last value.
+    assert_next_eq!(sample_stream, 4);
+    assert_closed!(sample_stream);
+
+    Ok(())
+}